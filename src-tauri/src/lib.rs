@@ -1,44 +1,316 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use tauri::Manager;
-use tauri_plugin_shell::process::CommandChild;
+use std::time::Duration;
+use tauri::{Emitter, Manager};
+use tauri_plugin_shell::process::{CommandChild, CommandEvent};
 use tauri_plugin_shell::ShellExt;
 use tokio::sync::Mutex;
 
-/// Shared state holding the backend port once the sidecar reports ready.
+/// Backoff/retry tuning for the backend supervisor (see `supervise_backend`).
+const RESTART_BACKOFF_INITIAL: Duration = Duration::from_millis(500);
+const RESTART_BACKOFF_MAX: Duration = Duration::from_secs(30);
+const RESTART_MAX_CONSECUTIVE_FAILURES: u32 = 8;
+/// A spawn that stays ready this long is considered healthy again, and resets
+/// the backoff/failure counters for the next crash.
+const RESTART_HEALTHY_RESET_AFTER: Duration = Duration::from_secs(60);
+
+type BackendEventRx = tauri::async_runtime::Receiver<CommandEvent>;
+
+/// Identifies one of potentially several server instances a user is running
+/// side by side. Chosen by whoever calls `start_instance` (e.g. a server name).
+type InstanceId = String;
+
+/// Tracks every instance the user has started, keyed by `InstanceId`. The
+/// registry only holds instances that are currently starting, running, or
+/// mid-shutdown — `stop_instance` and app exit both remove the entry.
+struct InstanceRegistry(std::sync::Mutex<HashMap<InstanceId, Arc<InstanceHandle>>>);
+
+/// Everything needed to run and supervise one backend/server instance. Each
+/// instance gets its own `CommandChild`, its own `BACKEND_READY:<port>`
+/// parsing, and — on Windows — its own per-instance `JobGuard`, so stopping
+/// one instance can never take down another.
+struct InstanceHandle {
+    id: InstanceId,
+    state: BackendState,
+    slot: BackendSlot,
+    supervisor: SupervisorState,
+}
+
+/// Info about one instance, as returned to the frontend by `list_instances`.
+#[derive(Clone, Serialize)]
+struct InstanceInfo {
+    id: InstanceId,
+    port: Option<u16>,
+}
+
+/// Holds the port for one instance once its sidecar reports ready. Reset to
+/// `None` by the supervisor whenever that instance's backend is down between
+/// (re)spawns, so callers never observe a stale port from a dead process.
 struct BackendState {
     port: Mutex<Option<u16>>,
 }
 
-/// Holds the backend sidecar process so we can kill it when the app exits.
+/// Holds one instance's currently-running backend sidecar, if any. The
+/// supervisor owns this slot: it swaps in a fresh `BackendChild` on every
+/// (re)spawn, and dropping the old value kills that process tree before the
+/// new one starts.
+struct BackendSlot(std::sync::Mutex<Option<BackendChild>>);
+
+/// Tells an instance's supervisor loop to stop respawning, set right before a
+/// deliberate shutdown so a kill doesn't get mistaken for a crash and
+/// resurrected.
+struct SupervisorState {
+    shutting_down: AtomicBool,
+}
+
+/// Configurable graceful-stop behavior, borrowed from watchexec's
+/// `--stop-signal`/`--stop-timeout`: how long to wait after asking an
+/// instance's backend to shut down on its own before force-killing it.
+/// Shared across every instance.
+struct BackendSettings(std::sync::Mutex<BackendSettingsInner>);
+
+#[derive(Clone, Copy)]
+struct BackendSettingsInner {
+    stop_timeout: Duration,
+    /// Signal sent for the soft-stop phase on Unix. Doesn't exist on Windows,
+    /// where the soft-stop phase always writes a shutdown line to the
+    /// sidecar's stdin instead of sending a signal (see `BackendChild`).
+    #[cfg(unix)]
+    stop_signal: libc::c_int,
+}
+
+impl Default for BackendSettingsInner {
+    fn default() -> Self {
+        Self {
+            stop_timeout: Duration::from_secs(10),
+            #[cfg(unix)]
+            stop_signal: libc::SIGTERM,
+        }
+    }
+}
+
+/// Windows-only: owns the single app-wide Job Object every spawned process
+/// (and its descendants, across every instance) is enrolled in. Kept as its
+/// own managed state rather than tying it to any one child, since it covers
+/// the whole process tree for as long as the app runs, across every respawn.
 ///
-/// On Windows a Job Object with `KILL_ON_JOB_CLOSE` is used to guarantee the
-/// backend is terminated even if the parent process crashes or is force-killed.
-/// The `Drop` impl is a secondary safety net for panics and normal teardown.
+/// Nothing reads the field directly — it's held purely for its `Drop` impl,
+/// so the job (and everything still in it) is torn down when the app's
+/// managed state goes away.
+#[cfg(windows)]
+struct AppJob(#[allow(dead_code)] Option<job::JobGuard>);
+
+/// Holds one running backend sidecar process so we can kill it on respawn or
+/// app exit.
+///
+/// On Windows every instance gets its own Job Object here, nested inside the
+/// app-wide one (`AppJob`) when that's available. The per-instance job is
+/// what actually isolates instances from each other: closing *this* job's
+/// handle reaps only this instance's sidecar and whatever it spawned, while
+/// the app-wide job remains the catch-all that reaps everything when the app
+/// itself goes down. `KILL_ON_JOB_CLOSE` is enforced by the kernel, so it
+/// still fires even if this process is itself `SIGKILL`ed (well,
+/// `TerminateProcess`'d) or crashes outright.
+///
+/// On Linux, `unix_reaper` is deliberately *not* a parent-death guarantee —
+/// it moves the sidecar into its own process group so `kill_hard`/`Drop` can
+/// take out the whole tree with a single `kill(-pgid, …)` on every normal
+/// teardown path (`stop_instance`, app exit, a respawn). That's plain
+/// userspace code: if this process is itself `SIGKILL`ed, nothing runs
+/// `kill_hard`/`Drop`, and the sidecar (and anything it spawned) is orphaned
+/// rather than reaped. A real guarantee would need the sidecar to carry its
+/// own `prctl(PR_SET_PDEATHSIG, SIGKILL)` (e.g. via an exec shim in front of
+/// it), which is a separate, larger change to how the sidecar is packaged and
+/// launched — this module only covers cleanup while the app is still alive
+/// to run it.
 struct BackendChild {
-    child: std::sync::Mutex<Option<CommandChild>>,
+    child: CommandChild,
     #[cfg(windows)]
-    _job: Option<job::JobGuard>,
+    _instance_job: Option<job::JobGuard>,
+    #[cfg(target_os = "linux")]
+    pgid: u32,
+    /// Pidfd for the direct sidecar process, when the kernel supports it
+    /// (Linux 5.3+). Preferred over signalling by raw pid because it can't be
+    /// confused with an unrelated process that reused the pid.
+    #[cfg(target_os = "linux")]
+    pidfd: Option<std::os::fd::RawFd>,
+}
+
+impl BackendChild {
+    #[cfg(windows)]
+    fn new(_app: &tauri::AppHandle, child: CommandChild) -> Self {
+        // Always give this instance its own job, nested under the app-wide
+        // one (see `AppJob`) when that exists. This is what lets stopping one
+        // instance reap only its own process tree instead of taking every
+        // other running instance down with it — relying solely on the
+        // app-wide job would mean the only way to kill one instance's
+        // descendants is to close the whole app.
+        let instance_job = job::assign_to_kill_on_close_job(child.pid());
+        Self {
+            child,
+            _instance_job: instance_job,
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    fn new(_app: &tauri::AppHandle, child: CommandChild) -> Self {
+        let pid = child.pid();
+        unix_reaper::move_to_own_group(pid);
+        let pidfd = unix_reaper::open_pidfd(pid);
+        Self {
+            child,
+            pgid: pid,
+            pidfd,
+        }
+    }
+
+    #[cfg(not(any(windows, target_os = "linux")))]
+    fn new(_app: &tauri::AppHandle, child: CommandChild) -> Self {
+        Self { child }
+    }
+
+    /// Ask this process to shut down on its own. Non-blocking — the caller is
+    /// responsible for waiting out the stop timeout and falling back to
+    /// [`kill_hard`](Self::kill_hard) if the process ignores it.
+    ///
+    /// On Unix this sends the configured signal (SIGTERM by default) to the
+    /// process and, on Linux, its whole group. On Windows there's no way to
+    /// target just the sidecar with a console control event here —
+    /// `GenerateConsoleCtrlEvent` only works on a process spawned into its
+    /// own group via `CREATE_NEW_PROCESS_GROUP`, and `tauri_plugin_shell`'s
+    /// sidecar builder has no way to request that flag — so instead this
+    /// writes a line to the sidecar's stdin, which the Python backend is
+    /// expected to treat as a graceful-shutdown request.
+    #[cfg(unix)]
+    fn soft_stop(&mut self, signal: libc::c_int) {
+        unsafe {
+            libc::kill(self.child.pid() as libc::pid_t, signal);
+        }
+        // Nudge the whole group too, so a Hytale world the backend is running
+        // gets the same chance to flush its state before the hard kill.
+        #[cfg(target_os = "linux")]
+        let _ = unix_reaper::kill_group(self.pgid, signal);
+    }
+
+    /// Windows counterpart of the Unix `soft_stop` above: writes a shutdown
+    /// line to the sidecar's stdin instead of sending a signal. Best-effort —
+    /// if the pipe is already gone there's nothing to fall back to except the
+    /// stop-timeout/`kill_hard` path the caller already has.
+    #[cfg(windows)]
+    fn soft_stop(&mut self) {
+        let _ = self.child.write(b"shutdown\n");
+    }
+
+    /// Terminate this process and, where possible, everything it spawned.
+    fn kill_hard(&mut self) {
+        #[cfg(target_os = "linux")]
+        if let Some(fd) = self.pidfd {
+            let _ = unix_reaper::signal_pidfd(fd, libc::SIGKILL);
+        }
+        let _ = self.child.kill();
+        // Also take out the whole process group, so a Hytale server the
+        // Python backend spawned doesn't survive as an orphan.
+        #[cfg(target_os = "linux")]
+        let _ = unix_reaper::kill_group(self.pgid, libc::SIGKILL);
+    }
 }
 
 impl Drop for BackendChild {
     fn drop(&mut self) {
-        if let Ok(mut guard) = self.child.lock() {
-            if let Some(child) = guard.take() {
-                let _ = child.kill();
+        self.kill_hard();
+        #[cfg(target_os = "linux")]
+        if let Some(fd) = self.pidfd.take() {
+            unsafe {
+                libc::close(fd);
             }
         }
     }
 }
 
-/// Kill the backend sidecar if it's still running. Safe to call multiple times.
-fn kill_backend_child<R: tauri::Runtime>(app: &impl tauri::Manager<R>) {
-    if let Some(backend) = app.try_state::<BackendChild>() {
-        if let Ok(mut guard) = backend.child.lock() {
-            if let Some(child) = guard.take() {
-                let _ = child.kill();
+/// Lock a `std::sync::Mutex`, recovering the inner value even if a previous
+/// holder panicked while holding it. One panicking command shouldn't poison
+/// shared state (`InstanceRegistry`, `BackendSettings`, a `BackendSlot`) and
+/// cascade into every other instance's commands failing too.
+fn lock_ignoring_poison<T>(mutex: &std::sync::Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Remove instance `id` from the registry. Called when the supervisor gives
+/// up on it for good (see `supervise_backend`), so a permanently-failed
+/// instance doesn't linger forever as a `port: None` entry that `list_instances`
+/// can't distinguish from "still starting" and that blocks `start_instance`
+/// from ever reusing the id.
+fn deregister_instance(app: &tauri::AppHandle, id: &InstanceId) {
+    lock_ignoring_poison(&app.state::<InstanceRegistry>().0).remove(id);
+}
+
+/// Force-kill one instance's backend sidecar if it's still running, and stop
+/// its supervisor from respawning it. This is the hard-kill safety net —
+/// prefer [`graceful_shutdown`] when there's time to let the backend exit
+/// cleanly. Safe to call multiple times.
+fn kill_backend_child(handle: &InstanceHandle) {
+    handle
+        .supervisor
+        .shutting_down
+        .store(true, Ordering::SeqCst);
+    lock_ignoring_poison(&handle.slot.0).take(); // dropped here, which runs `kill_hard`
+}
+
+/// Two-phase graceful stop, modeled on watchexec's `--stop-signal`/
+/// `--stop-timeout`: ask the instance's backend to shut down on its own, give
+/// it `BackendSettings.stop_timeout` to do so, then fall back to
+/// [`kill_backend_child`] if it's still around. Used for `stop_instance` and
+/// app exit; the plain hard kill remains the safety net for the Job Object.
+///
+/// See `BackendChild::soft_stop` for how the "ask nicely" phase differs
+/// between Unix (signal) and Windows (a shutdown line on stdin).
+async fn graceful_shutdown(app: &tauri::AppHandle, handle: &InstanceHandle) {
+    handle
+        .supervisor
+        .shutting_down
+        .store(true, Ordering::SeqCst);
+
+    let settings = *lock_ignoring_poison(&app.state::<BackendSettings>().0);
+
+    let soft_stopped = {
+        let mut guard = lock_ignoring_poison(&handle.slot.0);
+        match guard.as_mut() {
+            #[cfg(unix)]
+            Some(child) => {
+                child.soft_stop(settings.stop_signal);
+                true
+            }
+            #[cfg(windows)]
+            Some(child) => {
+                child.soft_stop();
+                true
             }
+            None => false,
+        }
+    };
+
+    if soft_stopped {
+        let deadline = std::time::Instant::now() + settings.stop_timeout;
+        loop {
+            if lock_ignoring_poison(&handle.slot.0).is_none() {
+                break; // exited cleanly within the timeout
+            }
+            if std::time::Instant::now() >= deadline {
+                eprintln!(
+                    "[Tauri] Instance '{}' did not stop within {:?}, force-killing",
+                    handle.id, settings.stop_timeout
+                );
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
         }
     }
+
+    kill_backend_child(handle);
 }
 
 // ---------------------------------------------------------------------------
@@ -51,10 +323,10 @@ mod job {
     use windows_sys::Win32::System::JobObjects::{
         AssignProcessToJobObject, CreateJobObjectW, JobObjectExtendedLimitInformation,
         SetInformationJobObject, JOBOBJECT_EXTENDED_LIMIT_INFORMATION,
-        JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE,
+        JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE, JOB_OBJECT_LIMIT_SILENT_BREAKAWAY_OK,
     };
     use windows_sys::Win32::System::Threading::{
-        OpenProcess, PROCESS_SET_QUOTA, PROCESS_TERMINATE,
+        GetCurrentProcess, OpenProcess, PROCESS_SET_QUOTA, PROCESS_TERMINATE,
     };
 
     /// RAII guard — closing the last handle to the job kills every process in it
@@ -72,30 +344,44 @@ mod job {
         }
     }
 
+    /// Create a job with `KILL_ON_JOB_CLOSE` + `SILENT_BREAKAWAY_OK` and hand
+    /// back its raw handle (caller assigns whichever process it likes).
+    unsafe fn create_kill_on_close_job() -> HANDLE {
+        let job = CreateJobObjectW(std::ptr::null(), std::ptr::null());
+        if job.is_null() {
+            return job;
+        }
+
+        let mut info: JOBOBJECT_EXTENDED_LIMIT_INFORMATION = mem::zeroed();
+        info.BasicLimitInformation.LimitFlags =
+            JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE | JOB_OBJECT_LIMIT_SILENT_BREAKAWAY_OK;
+
+        if SetInformationJobObject(
+            job,
+            JobObjectExtendedLimitInformation,
+            (&info as *const JOBOBJECT_EXTENDED_LIMIT_INFORMATION).cast(),
+            mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+        ) == 0
+        {
+            CloseHandle(job);
+            return std::ptr::null_mut();
+        }
+
+        job
+    }
+
     /// Create a Job Object with `KILL_ON_JOB_CLOSE` and assign `pid` to it.
     /// Returns `None` on failure (non-fatal — the event-based cleanup still
-    /// covers graceful exits).
+    /// covers graceful exits). Called once per instance (see
+    /// `BackendChild::new`) so each instance's process tree can be reaped on
+    /// its own, independently of the app-wide job from `assign_app_to_job`.
     pub fn assign_to_kill_on_close_job(pid: u32) -> Option<JobGuard> {
         unsafe {
-            let job = CreateJobObjectW(std::ptr::null(), std::ptr::null());
+            let job = create_kill_on_close_job();
             if job.is_null() {
                 return None;
             }
 
-            let mut info: JOBOBJECT_EXTENDED_LIMIT_INFORMATION = mem::zeroed();
-            info.BasicLimitInformation.LimitFlags = JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE;
-
-            if SetInformationJobObject(
-                job,
-                JobObjectExtendedLimitInformation,
-                (&info as *const JOBOBJECT_EXTENDED_LIMIT_INFORMATION).cast(),
-                mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
-            ) == 0
-            {
-                CloseHandle(job);
-                return None;
-            }
-
             let process = OpenProcess(PROCESS_SET_QUOTA | PROCESS_TERMINATE, 0, pid);
             if process.is_null() {
                 CloseHandle(job);
@@ -112,15 +398,468 @@ mod job {
             Some(JobGuard(job))
         }
     }
+
+    /// Create the app-wide job and assign the *current* process to it, so
+    /// every process this app spawns from here on (every instance's sidecar,
+    /// and anything a sidecar itself spawns) inherits membership and dies
+    /// with the job.
+    ///
+    /// If this process is already in a job, nested jobs let assignment still
+    /// succeed on Windows 8+; on older systems it fails unless the containing
+    /// job allows `SILENT_BREAKAWAY_OK`. Either way, each instance still gets
+    /// its own job via `assign_to_kill_on_close_job` regardless of whether
+    /// this one was established — losing the app-wide catch-all just means
+    /// the per-instance jobs are the only thing reaping their trees.
+    pub fn assign_app_to_job() -> Option<JobGuard> {
+        unsafe {
+            let job = create_kill_on_close_job();
+            if job.is_null() {
+                return None;
+            }
+
+            let assigned = AssignProcessToJobObject(job, GetCurrentProcess());
+            if assigned == 0 {
+                // Most likely cause: already in a job that doesn't allow nesting
+                // or breakaway. Let the caller fall back to per-instance jobs.
+                CloseHandle(job);
+                return None;
+            }
+
+            Some(JobGuard(job))
+        }
+    }
 }
 
-/// Tauri command: return the backend port (or 0 if not ready yet).
+// ---------------------------------------------------------------------------
+// Linux: process-group cleanup for normal teardown (see the note on
+// `BackendChild` for why this is weaker than the Windows Job Object — it
+// can't save us if this process itself is SIGKILLed)
+// ---------------------------------------------------------------------------
+#[cfg(target_os = "linux")]
+mod unix_reaper {
+    use libc::pid_t;
+    use std::io;
+    use std::os::fd::RawFd;
+
+    /// Move `pid` into its own process group (`pgid == pid`) right after spawn,
+    /// so a later `kill(-pgid, …)` reaches it and every descendant it spawns
+    /// (e.g. the actual Hytale server the Python backend launches), not just
+    /// siblings still sitting in our own group.
+    ///
+    /// There's an inherent race between spawn and this call, but since nothing
+    /// else talks to the child in that window it's the same approach
+    /// async-process uses when asked to detach a child into its own group.
+    pub fn move_to_own_group(pid: u32) {
+        unsafe {
+            libc::setpgid(pid as pid_t, 0);
+        }
+    }
+
+    /// Open a pidfd for `pid` (Linux 5.3+). `None` on older kernels or if the
+    /// syscall isn't available, in which case callers fall back to signalling
+    /// by pid/pgid directly.
+    pub fn open_pidfd(pid: u32) -> Option<RawFd> {
+        let fd = unsafe { libc::syscall(libc::SYS_pidfd_open, pid as pid_t, 0) };
+        if fd < 0 {
+            None
+        } else {
+            Some(fd as RawFd)
+        }
+    }
+
+    /// Signal `pid` via its pidfd if we have one open, which — unlike a plain
+    /// `kill(pid, …)` — can't accidentally hit a reused pid once the original
+    /// process has exited.
+    pub fn signal_pidfd(fd: RawFd, signal: libc::c_int) -> io::Result<()> {
+        let rc = unsafe { libc::syscall(libc::SYS_pidfd_send_signal, fd, signal, 0, 0) };
+        if rc < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+
+    /// Signal every process in `pid`'s process group (`pid` must already be
+    /// its own group leader via [`move_to_own_group`]).
+    pub fn kill_group(pid: u32, signal: libc::c_int) -> io::Result<()> {
+        if unsafe { libc::kill(-(pid as pid_t), signal) } == -1 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+}
+
+/// Tauri command: list every instance the user has started, with its port if
+/// it's ready.
 #[tauri::command]
-async fn get_backend_port(state: tauri::State<'_, Arc<BackendState>>) -> Result<u16, String> {
-    let lock = state.port.lock().await;
+async fn list_instances(
+    registry: tauri::State<'_, InstanceRegistry>,
+) -> Result<Vec<InstanceInfo>, String> {
+    let handles: Vec<Arc<InstanceHandle>> = lock_ignoring_poison(&registry.0)
+        .values()
+        .cloned()
+        .collect();
+    let mut infos = Vec::with_capacity(handles.len());
+    for handle in handles {
+        let port = *handle.state.port.lock().await;
+        infos.push(InstanceInfo {
+            id: handle.id.clone(),
+            port,
+        });
+    }
+    Ok(infos)
+}
+
+/// Tauri command: launch and supervise a new instance under `id`. Errors if an
+/// instance with that id is already running.
+#[tauri::command]
+async fn start_instance(
+    app: tauri::AppHandle,
+    registry: tauri::State<'_, InstanceRegistry>,
+    id: InstanceId,
+) -> Result<(), String> {
+    let handle = {
+        let mut guard = lock_ignoring_poison(&registry.0);
+        if guard.contains_key(&id) {
+            return Err(format!("instance '{id}' is already running"));
+        }
+        let handle = Arc::new(InstanceHandle {
+            id: id.clone(),
+            state: BackendState {
+                port: Mutex::new(None),
+            },
+            slot: BackendSlot(std::sync::Mutex::new(None)),
+            supervisor: SupervisorState {
+                shutting_down: AtomicBool::new(false),
+            },
+        });
+        // Clone while still holding the lock — a concurrent `stop_instance`
+        // can't remove this entry out from under us between the insert and a
+        // separate re-lock-and-get.
+        guard.insert(id.clone(), handle.clone());
+        handle
+    };
+
+    tauri::async_runtime::spawn(async move {
+        supervise_backend(app, handle).await;
+    });
+
+    Ok(())
+}
+
+/// Tauri command: gracefully stop and forget the instance under `id`.
+#[tauri::command]
+async fn stop_instance(
+    app: tauri::AppHandle,
+    registry: tauri::State<'_, InstanceRegistry>,
+    id: InstanceId,
+) -> Result<(), String> {
+    // Keep the entry in the registry for the duration of the shutdown — it's
+    // still "mid-shutdown" per `InstanceRegistry`'s own contract, and a
+    // `start_instance` for the same id needs to see it and reject, not race
+    // a second sidecar into existence before this one's port is released.
+    let handle = {
+        let guard = lock_ignoring_poison(&registry.0);
+        guard
+            .get(&id)
+            .cloned()
+            .ok_or_else(|| format!("no such instance '{id}'"))?
+    };
+    graceful_shutdown(&app, &handle).await;
+    lock_ignoring_poison(&registry.0).remove(&id);
+    Ok(())
+}
+
+/// Tauri command: return the port for instance `id` (or an error if it isn't
+/// running or isn't ready yet).
+#[tauri::command]
+async fn get_instance_port(
+    registry: tauri::State<'_, InstanceRegistry>,
+    id: InstanceId,
+) -> Result<u16, String> {
+    let handle = lock_ignoring_poison(&registry.0)
+        .get(&id)
+        .cloned()
+        .ok_or_else(|| format!("no such instance '{id}'"))?;
+    let lock = handle.state.port.lock().await;
     lock.ok_or_else(|| "Backend not ready yet".into())
 }
 
+/// Tauri command: configure the graceful-stop timeout and (Unix-only) signal
+/// used by `graceful_shutdown`, shared by every instance.
+///
+/// `signal` only means anything on Unix — Windows' soft-stop phase always
+/// writes the same shutdown line to the sidecar's stdin (see
+/// `BackendChild::soft_stop`) and has no signal to select, so a caller asking
+/// for one there gets an error instead of having the choice silently ignored.
+#[tauri::command]
+fn set_backend_stop_config(
+    settings: tauri::State<'_, BackendSettings>,
+    timeout_ms: u64,
+    signal: Option<String>,
+) -> Result<(), String> {
+    #[cfg(not(unix))]
+    if signal.is_some() {
+        return Err(
+            "stop signal selection isn't supported on this platform; the soft-stop phase here \
+             always writes a shutdown line to the sidecar's stdin"
+                .into(),
+        );
+    }
+
+    let mut inner = lock_ignoring_poison(&settings.0);
+    inner.stop_timeout = Duration::from_millis(timeout_ms);
+
+    #[cfg(unix)]
+    if let Some(name) = signal {
+        inner.stop_signal = match name.as_str() {
+            "SIGTERM" => libc::SIGTERM,
+            "SIGINT" => libc::SIGINT,
+            "SIGHUP" => libc::SIGHUP,
+            other => return Err(format!("unsupported stop signal: {other}")),
+        };
+    }
+
+    Ok(())
+}
+
+/// Spawn the Python sidecar backend for one instance. Pulled out of
+/// `supervise_backend` so every restart attempt goes through the exact same
+/// path as the first spawn.
+fn spawn_backend_child(
+    app: &tauri::AppHandle,
+    id: &InstanceId,
+) -> Result<(BackendEventRx, CommandChild), String> {
+    let sidecar = app
+        .shell()
+        .sidecar("server-manager-backend")
+        .map_err(|e| e.to_string())?
+        .args(["--instance-id", id]);
+    sidecar.spawn().map_err(|e| e.to_string())
+}
+
+/// Outcome of one step of the supervisor's backoff/give-up bookkeeping,
+/// computed by [`next_restart_decision`].
+struct RestartDecision {
+    /// Backoff to sleep for before the next respawn attempt, if not giving up.
+    backoff: Duration,
+    consecutive_failures: u32,
+    give_up: bool,
+}
+
+/// Pure backoff/give-up/healthy-reset bookkeeping for `supervise_backend`,
+/// pulled out into its own function so it can be unit-tested without
+/// spawning a real process. `became_healthy` is whether the attempt that just
+/// ended counts as healthy (stayed ready for at least
+/// `RESTART_HEALTHY_RESET_AFTER`) — always `false` for a spawn failure, which
+/// never got the chance to become ready.
+fn next_restart_decision(
+    backoff: Duration,
+    consecutive_failures: u32,
+    became_healthy: bool,
+) -> RestartDecision {
+    let (backoff, consecutive_failures) = if became_healthy {
+        (RESTART_BACKOFF_INITIAL, 0)
+    } else {
+        (backoff, consecutive_failures)
+    };
+    let consecutive_failures = consecutive_failures + 1;
+    RestartDecision {
+        give_up: consecutive_failures > RESTART_MAX_CONSECUTIVE_FAILURES,
+        backoff,
+        consecutive_failures,
+    }
+}
+
+/// Owns one instance's backend sidecar lifecycle: spawn it, wait for
+/// `BACKEND_READY:`, and if it ever exits unexpectedly, respawn it with
+/// exponential backoff instead of leaving its port pointing at a dead process
+/// forever.
+///
+/// Backoff doubles from `RESTART_BACKOFF_INITIAL` up to `RESTART_BACKOFF_MAX`
+/// after each unhealthy spawn, and resets once a spawn stays up for
+/// `RESTART_HEALTHY_RESET_AFTER`. Once more than
+/// `RESTART_MAX_CONSECUTIVE_FAILURES` happen in a row, the supervisor gives up
+/// and emits `backend://<id>/failed`.
+async fn supervise_backend(app: tauri::AppHandle, handle: Arc<InstanceHandle>) {
+    let mut backoff = RESTART_BACKOFF_INITIAL;
+    let mut consecutive_failures: u32 = 0;
+    let restarting_event = format!("backend://{}/restarting", handle.id);
+    let ready_event = format!("backend://{}/ready", handle.id);
+    let failed_event = format!("backend://{}/failed", handle.id);
+
+    loop {
+        if handle.supervisor.shutting_down.load(Ordering::SeqCst) {
+            return;
+        }
+
+        let (mut rx, child) = match spawn_backend_child(&app, &handle.id) {
+            Ok(pair) => pair,
+            Err(err) => {
+                eprintln!(
+                    "[Tauri] Failed to spawn backend sidecar for '{}': {err}",
+                    handle.id
+                );
+                let decision = next_restart_decision(backoff, consecutive_failures, false);
+                consecutive_failures = decision.consecutive_failures;
+                if decision.give_up {
+                    eprintln!(
+                        "[Tauri] Instance '{}' failed to spawn too many times, giving up",
+                        handle.id
+                    );
+                    let _ = app.emit(&failed_event, ());
+                    deregister_instance(&app, &handle.id);
+                    return;
+                }
+                let _ = app.emit(&restarting_event, ());
+                tokio::time::sleep(decision.backoff).await;
+                backoff = (decision.backoff * 2).min(RESTART_BACKOFF_MAX);
+                continue;
+            }
+        };
+
+        let backend_child = BackendChild::new(&app, child);
+        *lock_ignoring_poison(&handle.slot.0) = Some(backend_child);
+
+        let mut ready_at: Option<std::time::Instant> = None;
+
+        while let Some(event) = rx.recv().await {
+            match event {
+                CommandEvent::Stdout(line_bytes) => {
+                    let line = String::from_utf8_lossy(&line_bytes);
+                    if let Some(port_str) = line.trim().strip_prefix("BACKEND_READY:") {
+                        if let Ok(port) = port_str.parse::<u16>() {
+                            *handle.state.port.lock().await = Some(port);
+                            ready_at.get_or_insert_with(std::time::Instant::now);
+                            println!("[Tauri] Instance '{}' ready on port {port}", handle.id);
+                            let _ = app.emit(&ready_event, port);
+                        }
+                    }
+                }
+                CommandEvent::Terminated(payload) => {
+                    println!(
+                        "[Tauri] Instance '{}' sidecar terminated unexpectedly: {payload:?}",
+                        handle.id
+                    );
+                    break;
+                }
+                _ => {}
+            }
+        }
+
+        // The child is gone either way (terminated, or the event channel
+        // closed) — clear the slot now rather than leaving the dead
+        // `BackendChild` sitting there until the next spawn. Otherwise
+        // `graceful_shutdown`'s poll loop can't tell a dead process from a
+        // live one during the backoff sleep below, and blocks for the full
+        // `stop_timeout` against a crash-looping instance for nothing.
+        lock_ignoring_poison(&handle.slot.0).take();
+
+        if handle.supervisor.shutting_down.load(Ordering::SeqCst) {
+            return;
+        }
+
+        *handle.state.port.lock().await = None;
+
+        let became_healthy =
+            ready_at.is_some_and(|at| at.elapsed() >= RESTART_HEALTHY_RESET_AFTER);
+        let decision = next_restart_decision(backoff, consecutive_failures, became_healthy);
+        consecutive_failures = decision.consecutive_failures;
+        if decision.give_up {
+            eprintln!(
+                "[Tauri] Instance '{}' crashed {consecutive_failures} times in a row, giving up",
+                handle.id
+            );
+            let _ = app.emit(&failed_event, ());
+            deregister_instance(&app, &handle.id);
+            return;
+        }
+
+        let _ = app.emit(&restarting_event, ());
+        tokio::time::sleep(decision.backoff).await;
+        backoff = (decision.backoff * 2).min(RESTART_BACKOFF_MAX);
+    }
+}
+
+#[cfg(test)]
+mod restart_decision_tests {
+    use super::*;
+
+    #[test]
+    fn first_failure_uses_initial_backoff() {
+        let decision = next_restart_decision(RESTART_BACKOFF_INITIAL, 0, false);
+        assert_eq!(decision.consecutive_failures, 1);
+        assert!(!decision.give_up);
+        assert_eq!(decision.backoff, RESTART_BACKOFF_INITIAL);
+    }
+
+    #[test]
+    fn gives_up_after_max_consecutive_failures() {
+        let decision = next_restart_decision(
+            RESTART_BACKOFF_INITIAL,
+            RESTART_MAX_CONSECUTIVE_FAILURES,
+            false,
+        );
+        assert_eq!(
+            decision.consecutive_failures,
+            RESTART_MAX_CONSECUTIVE_FAILURES + 1
+        );
+        assert!(decision.give_up);
+    }
+
+    #[test]
+    fn does_not_give_up_one_failure_short_of_the_limit() {
+        let decision = next_restart_decision(
+            RESTART_BACKOFF_INITIAL,
+            RESTART_MAX_CONSECUTIVE_FAILURES - 1,
+            false,
+        );
+        assert!(!decision.give_up);
+    }
+
+    #[test]
+    fn healthy_spawn_resets_backoff_and_failure_count() {
+        let decision = next_restart_decision(RESTART_BACKOFF_MAX, 7, true);
+        assert_eq!(decision.backoff, RESTART_BACKOFF_INITIAL);
+        assert_eq!(decision.consecutive_failures, 1);
+        assert!(!decision.give_up);
+    }
+}
+
+/// Gracefully stop every instance still in the registry, concurrently, and
+/// empty it. Used on app exit so no instance is left dangling just because
+/// another instance's stop timeout is still running.
+async fn shutdown_all_instances(app: &tauri::AppHandle) {
+    let handles: Vec<Arc<InstanceHandle>> = {
+        let mut registry = lock_ignoring_poison(&app.state::<InstanceRegistry>().0);
+        registry.drain().map(|(_, handle)| handle).collect()
+    };
+
+    let tasks: Vec<_> = handles
+        .into_iter()
+        .map(|handle| {
+            let app = app.clone();
+            tauri::async_runtime::spawn(async move {
+                graceful_shutdown(&app, &handle).await;
+            })
+        })
+        .collect();
+
+    for task in tasks {
+        let _ = task.await;
+    }
+}
+
+/// Force-kill every instance still in the registry. The hard-kill safety net
+/// for `Exit`, mirroring `kill_backend_child` for the single-instance case.
+fn kill_all_instances<R: tauri::Runtime>(app: &impl tauri::Manager<R>) {
+    if let Some(registry) = app.try_state::<InstanceRegistry>() {
+        let mut guard = lock_ignoring_poison(&registry.0);
+        for (_, handle) in guard.drain() {
+            kill_backend_child(&handle);
+        }
+    }
+}
+
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_window_state::Builder::new().build())
@@ -128,66 +867,54 @@ pub fn run() {
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_opener::init())
         .setup(|app| {
-            let state = Arc::new(BackendState {
-                port: Mutex::new(None),
-            });
-            app.manage(state.clone());
-
-            // Spawn the Python sidecar backend
-            let shell = app.shell();
-            let sidecar = shell
-                .sidecar("server-manager-backend")
-                .expect("failed to create sidecar command");
-
-            let (mut rx, child) = sidecar.spawn().expect("failed to spawn sidecar");
+            app.manage(InstanceRegistry(std::sync::Mutex::new(HashMap::new())));
+            app.manage(BackendSettings(std::sync::Mutex::new(
+                BackendSettingsInner::default(),
+            )));
 
-            // On Windows, bind the backend to a Job Object so it is automatically
-            // killed if this process exits for any reason (crash, force-kill, etc.).
+            // On Windows, enroll this process (and therefore every process it
+            // spawns from here on, transitively, across every instance) in a
+            // single app-wide Job Object with KILL_ON_JOB_CLOSE, so the whole
+            // tree dies when the job closes.
             #[cfg(windows)]
-            let job_guard = {
-                let guard = job::assign_to_kill_on_close_job(child.pid());
-                if guard.is_none() {
+            {
+                let app_job = job::assign_app_to_job();
+                if app_job.is_none() {
                     eprintln!(
-                        "[Tauri] Warning: could not create kill-on-close job object for backend"
+                        "[Tauri] Warning: could not assign app to a job object (already in a \
+                         non-nestable job?) — falling back to per-instance job objects"
                     );
                 }
-                guard
-            };
-
-            app.manage(BackendChild {
-                child: std::sync::Mutex::new(Some(child)),
-                #[cfg(windows)]
-                _job: job_guard,
-            });
-
-            // Listen for the BACKEND_READY:<port> line on stdout
-            let state_clone = state.clone();
-            tauri::async_runtime::spawn(async move {
-                use tauri_plugin_shell::process::CommandEvent;
-                while let Some(event) = rx.recv().await {
-                    if let CommandEvent::Stdout(line_bytes) = event {
-                        let line = String::from_utf8_lossy(&line_bytes);
-                        if let Some(port_str) = line.trim().strip_prefix("BACKEND_READY:") {
-                            if let Ok(port) = port_str.parse::<u16>() {
-                                let mut lock = state_clone.port.lock().await;
-                                *lock = Some(port);
-                                println!("[Tauri] Backend ready on port {port}");
-                            }
-                        }
-                    }
-                }
-            });
+                app.manage(AppJob(app_job));
+            }
 
             Ok(())
         })
-        .invoke_handler(tauri::generate_handler![get_backend_port])
-        // Backend is killed gracefully in ExitRequested, and again in Exit as a
-        // safety net. The Job Object (Windows) handles the truly catastrophic cases.
+        .invoke_handler(tauri::generate_handler![
+            list_instances,
+            start_instance,
+            stop_instance,
+            get_instance_port,
+            set_backend_stop_config
+        ])
+        // Every instance is stopped gracefully in ExitRequested, and force-killed
+        // again in Exit as a safety net. The Job Object (Windows) handles the
+        // truly catastrophic cases.
         .build(tauri::generate_context!())
         .expect("error while building tauri application")
         .run(|app_handle, event| match event {
-            tauri::RunEvent::ExitRequested { .. } | tauri::RunEvent::Exit => {
-                kill_backend_child(app_handle);
+            tauri::RunEvent::ExitRequested { api, .. } => {
+                // Give every instance a chance to shut down cleanly before the
+                // app actually exits.
+                api.prevent_exit();
+                let app_handle = app_handle.clone();
+                tauri::async_runtime::spawn(async move {
+                    shutdown_all_instances(&app_handle).await;
+                    app_handle.exit(0);
+                });
+            }
+            tauri::RunEvent::Exit => {
+                kill_all_instances(app_handle);
             }
             _ => {}
         });